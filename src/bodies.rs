@@ -1,3 +1,4 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 use std::fmt::Formatter;
 use std::str::FromStr;
@@ -5,23 +6,106 @@ use std::str::FromStr;
 // TODO: Figure out a better way to load body data, this is a hacky quick solution.
 pub fn get_body(body: &CentralBody) -> Body {
     match body {
-        CentralBody::EARTH => Body { mu: 398600.4418E9 }
+        CentralBody::SUN => Body {
+            mu: 1.32712440018E20,
+            radius: 6.9634E8,
+            j2: 0.0,
+            rotation_rate: 2.865E-6,
+        },
+        CentralBody::MERCURY => Body {
+            mu: 2.2032E13,
+            radius: 2.4397E6,
+            j2: 6.0E-5,
+            rotation_rate: 1.24E-6,
+        },
+        CentralBody::VENUS => Body {
+            mu: 3.24859E14,
+            radius: 6.0518E6,
+            j2: 4.458E-6,
+            rotation_rate: -2.99E-7,
+        },
+        CentralBody::EARTH => Body {
+            mu: 398600.4418E9,
+            radius: 6378137.0,
+            j2: 1.08263E-3,
+            rotation_rate: 7.2921159E-5,
+        },
+        CentralBody::MOON => Body {
+            mu: 4.9048695E12,
+            radius: 1.7374E6,
+            j2: 2.027E-4,
+            rotation_rate: 2.6617E-6,
+        },
+        CentralBody::MARS => Body {
+            mu: 4.282837E13,
+            radius: 3.3962E6,
+            j2: 1.96045E-3,
+            rotation_rate: 7.088218E-5,
+        },
+        CentralBody::JUPITER => Body {
+            mu: 1.26686534E17,
+            radius: 7.1492E7,
+            j2: 1.4736E-2,
+            rotation_rate: 1.7585E-4,
+        },
+        CentralBody::SATURN => Body {
+            mu: 3.7931187E16,
+            radius: 6.0268E7,
+            j2: 1.6298E-2,
+            rotation_rate: 1.637884E-4,
+        },
+        CentralBody::URANUS => Body {
+            mu: 5.793939E15,
+            radius: 2.5559E7,
+            j2: 3.34343E-3,
+            rotation_rate: -1.012E-4,
+        },
+        CentralBody::NEPTUNE => Body {
+            mu: 6.836529E15,
+            radius: 2.4764E7,
+            j2: 3.411E-3,
+            rotation_rate: 1.083E-4,
+        },
     }
 }
 
 pub struct Body {
     pub mu: f64,
+    /// Equatorial radius, in meters.
+    pub radius: f64,
+    /// J2 zonal-harmonic coefficient describing the body's oblateness.
+    pub j2: f64,
+    /// Sidereal rotation rate about the body's spin axis, in rad/s.
+    pub rotation_rate: f64,
 }
 
 #[derive(PartialEq, Debug)]
 pub enum CentralBody {
+    SUN,
+    MERCURY,
+    VENUS,
     EARTH,
+    MOON,
+    MARS,
+    JUPITER,
+    SATURN,
+    URANUS,
+    NEPTUNE,
 }
 
 impl fmt::Display for CentralBody {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
+            CentralBody::SUN => write!(f, "SUN"),
+            CentralBody::MERCURY => write!(f, "MERCURY"),
+            CentralBody::VENUS => write!(f, "VENUS"),
             CentralBody::EARTH => write!(f, "EARTH"),
+            CentralBody::MOON => write!(f, "MOON"),
+            CentralBody::MARS => write!(f, "MARS"),
+            CentralBody::JUPITER => write!(f, "JUPITER"),
+            CentralBody::SATURN => write!(f, "SATURN"),
+            CentralBody::URANUS => write!(f, "URANUS"),
+            CentralBody::NEPTUNE => write!(f, "NEPTUNE"),
         }
     }
 }
@@ -31,23 +115,100 @@ impl FromStr for CentralBody {
 
     fn from_str(input: &str) -> Result<CentralBody, Self::Err> {
         match input {
+            "SUN" => Ok(CentralBody::SUN),
+            "MERCURY" => Ok(CentralBody::MERCURY),
+            "VENUS" => Ok(CentralBody::VENUS),
             "EARTH" => Ok(CentralBody::EARTH),
+            "MOON" => Ok(CentralBody::MOON),
+            "MARS" => Ok(CentralBody::MARS),
+            "JUPITER" => Ok(CentralBody::JUPITER),
+            "SATURN" => Ok(CentralBody::SATURN),
+            "URANUS" => Ok(CentralBody::URANUS),
+            "NEPTUNE" => Ok(CentralBody::NEPTUNE),
             _ => Err(()),
         }
     }
 }
 
+impl Serialize for CentralBody {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CentralBody {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        CentralBody::from_str(&value)
+            .map_err(|_| serde::de::Error::custom(format!("invalid CentralBody: {}", value)))
+    }
+}
+
 #[cfg(test)]
 mod core_tests {
     use super::*;
 
     #[test]
     fn test_central_body_enum_supports_to_string() {
+        assert_eq!(CentralBody::SUN.to_string(), "SUN");
+        assert_eq!(CentralBody::MERCURY.to_string(), "MERCURY");
+        assert_eq!(CentralBody::VENUS.to_string(), "VENUS");
         assert_eq!(CentralBody::EARTH.to_string(), "EARTH");
+        assert_eq!(CentralBody::MOON.to_string(), "MOON");
+        assert_eq!(CentralBody::MARS.to_string(), "MARS");
+        assert_eq!(CentralBody::JUPITER.to_string(), "JUPITER");
+        assert_eq!(CentralBody::SATURN.to_string(), "SATURN");
+        assert_eq!(CentralBody::URANUS.to_string(), "URANUS");
+        assert_eq!(CentralBody::NEPTUNE.to_string(), "NEPTUNE");
     }
 
     #[test]
     fn test_central_body_enum_supports_from_str() {
+        assert_eq!(CentralBody::from_str("SUN").unwrap(), CentralBody::SUN);
+        assert_eq!(CentralBody::from_str("MERCURY").unwrap(), CentralBody::MERCURY);
+        assert_eq!(CentralBody::from_str("VENUS").unwrap(), CentralBody::VENUS);
         assert_eq!(CentralBody::from_str("EARTH").unwrap(), CentralBody::EARTH);
+        assert_eq!(CentralBody::from_str("MOON").unwrap(), CentralBody::MOON);
+        assert_eq!(CentralBody::from_str("MARS").unwrap(), CentralBody::MARS);
+        assert_eq!(CentralBody::from_str("JUPITER").unwrap(), CentralBody::JUPITER);
+        assert_eq!(CentralBody::from_str("SATURN").unwrap(), CentralBody::SATURN);
+        assert_eq!(CentralBody::from_str("URANUS").unwrap(), CentralBody::URANUS);
+        assert_eq!(CentralBody::from_str("NEPTUNE").unwrap(), CentralBody::NEPTUNE);
+    }
+
+    #[test]
+    fn test_central_body_serializes_as_json_string() {
+        assert_eq!(
+            serde_json::to_string(&CentralBody::EARTH).unwrap(),
+            "\"EARTH\""
+        );
+    }
+
+    #[test]
+    fn test_central_body_round_trips_through_json() {
+        let json = serde_json::to_string(&CentralBody::SATURN).unwrap();
+        let recovered: CentralBody = serde_json::from_str(&json).unwrap();
+        assert_eq!(recovered, CentralBody::SATURN);
+    }
+
+    #[test]
+    fn test_central_body_rejects_unknown_json_value() {
+        let result: Result<CentralBody, _> = serde_json::from_str("\"PLUTO\"");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_get_body_returns_distinct_constants_per_body() {
+        let earth = get_body(&CentralBody::EARTH);
+        let moon = get_body(&CentralBody::MOON);
+
+        assert_ne!(earth.mu, moon.mu);
+        assert_ne!(earth.radius, moon.radius);
     }
 }
@@ -0,0 +1,135 @@
+use nalgebra::Vector6;
+use std::f64::consts::PI;
+
+/// Earth's rotation rate about its spin axis, in rad/s.
+const OMEGA_EARTH: f64 = 7.2921159E-5;
+
+/// Sidereal-to-solar day ratio used to advance the Earth rotation angle per Julian day.
+const SIDEREAL_DAYS_PER_SOLAR_DAY: f64 = 1.0027378119113546;
+
+/// Rotates a state vector from the Earth-Centered Inertial (ECI) frame to the
+/// Earth-Centered Earth-Fixed (ECEF) frame at the given epoch.
+///
+/// `jd2000` is the epoch expressed as Julian days since the J2000.0 epoch; it is used
+/// to compute the Earth rotation angle theta applied to the position, with the
+/// corresponding omega x r transport term subtracted from the velocity.
+pub fn eci_to_ecef(state: Vector6<f64>, jd2000: f64) -> Vector6<f64> {
+    let theta = 2.0 * PI * (0.779057273264 + SIDEREAL_DAYS_PER_SOLAR_DAY * jd2000);
+    let (sin_theta, cos_theta) = theta.sin_cos();
+
+    let x = state[0];
+    let y = state[1];
+    let z = state[2];
+    let vx = state[3];
+    let vy = state[4];
+    let vz = state[5];
+
+    let x_ecef = x * cos_theta + y * sin_theta;
+    let y_ecef = -x * sin_theta + y * cos_theta;
+    let z_ecef = z;
+
+    let vx_ecef = (vx * cos_theta + vy * sin_theta) + OMEGA_EARTH * y_ecef;
+    let vy_ecef = (-vx * sin_theta + vy * cos_theta) - OMEGA_EARTH * x_ecef;
+    let vz_ecef = vz;
+
+    Vector6::new(x_ecef, y_ecef, z_ecef, vx_ecef, vy_ecef, vz_ecef)
+}
+
+/// WGS-72 equatorial radius, in meters.
+const WGS72_EQUATORIAL_RADIUS: f64 = 6378135.0;
+/// WGS-72 flattening.
+const WGS72_FLATTENING: f64 = 1.0 / 298.26;
+
+/// Converts an ECEF position into WGS-72 geodetic coordinates, returning
+/// `(latitude, longitude, altitude)` in radians/radians/meters.
+///
+/// Longitude and an initial latitude guess are computed directly; latitude is
+/// then refined by the standard iterative scheme until successive estimates
+/// differ by less than `1e-10`.
+pub fn ecef_to_geodetic(position: nalgebra::Vector3<f64>) -> (f64, f64, f64) {
+    let e2 = WGS72_FLATTENING * (2.0 - WGS72_FLATTENING);
+
+    let x = position[0];
+    let y = position[1];
+    let z = position[2];
+
+    let lon = y.atan2(x);
+    let r = (x.powi(2) + y.powi(2)).sqrt();
+    let mut lat = z.atan2(r);
+
+    loop {
+        let c = 1.0 / (1.0 - e2 * lat.sin().powi(2)).sqrt();
+        let next_lat = (z + WGS72_EQUATORIAL_RADIUS * c * e2 * lat.sin()).atan2(r);
+        let converged = (next_lat - lat).abs() < 1e-10;
+        lat = next_lat;
+        if converged {
+            break;
+        }
+    }
+
+    let c = 1.0 / (1.0 - e2 * lat.sin().powi(2)).sqrt();
+    let alt = r / lat.cos() - WGS72_EQUATORIAL_RADIUS * c;
+
+    (lat, lon, alt)
+}
+
+/// Converts a whole propagated ECEF trajectory into a ground track, i.e. a
+/// `Vec` of `(latitude, longitude, altitude)` geodetic samples, one per state.
+pub fn ground_track(states: &[Vector6<f64>]) -> Vec<(f64, f64, f64)> {
+    states
+        .iter()
+        .map(|state| ecef_to_geodetic(nalgebra::Vector3::new(state[0], state[1], state[2])))
+        .collect()
+}
+
+#[cfg(test)]
+mod frames_tests {
+    use super::*;
+
+    #[test]
+    fn test_eci_to_ecef_preserves_position_magnitude() {
+        let state = Vector6::new(7000000.0, 0.0, 0.0, 0.0, 7500.0, 0.0);
+        let ecef = eci_to_ecef(state, 0.123);
+
+        let eci_radius = (state[0].powi(2) + state[1].powi(2)).sqrt();
+        let ecef_radius = (ecef[0].powi(2) + ecef[1].powi(2)).sqrt();
+        assert!((eci_radius - ecef_radius).abs() < 1e-6);
+        assert_eq!(ecef[2], state[2]);
+    }
+
+    #[test]
+    fn test_eci_to_ecef_zero_rotation_is_identity() {
+        let state = Vector6::new(7000000.0, 0.0, 0.0, 0.0, 7500.0, 0.0);
+        // theta = 0 when 0.779057273264 + SIDEREAL_DAYS_PER_SOLAR_DAY * jd2000 is an integer;
+        // solve for the jd2000 that cancels the constant offset term.
+        let jd2000 = -0.779057273264 / SIDEREAL_DAYS_PER_SOLAR_DAY;
+        let ecef = eci_to_ecef(state, jd2000);
+
+        assert!((ecef[0] - state[0]).abs() < 1e-6);
+        assert!((ecef[1] - state[1]).abs() < 1e-6);
+        assert_eq!(ecef[2], state[2]);
+    }
+
+    #[test]
+    fn test_ecef_to_geodetic_equatorial_point() {
+        let position = nalgebra::Vector3::new(WGS72_EQUATORIAL_RADIUS, 0.0, 0.0);
+        let (lat, lon, alt) = ecef_to_geodetic(position);
+
+        assert!(lat.abs() < 1e-9);
+        assert!(lon.abs() < 1e-9);
+        assert!(alt.abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_ground_track_maps_each_state() {
+        let states = vec![
+            Vector6::new(WGS72_EQUATORIAL_RADIUS, 0.0, 0.0, 0.0, 0.0, 0.0),
+            Vector6::new(0.0, WGS72_EQUATORIAL_RADIUS, 0.0, 0.0, 0.0, 0.0),
+        ];
+        let track = ground_track(&states);
+
+        assert_eq!(track.len(), 2);
+        assert!(track[0].1.abs() < 1e-9);
+        assert!((track[1].1 - PI / 2.0).abs() < 1e-9);
+    }
+}
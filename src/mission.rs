@@ -1,10 +1,25 @@
-use crate::orbit::Orbit;
+use crate::frames::eci_to_ecef;
+use crate::orbit::{CoordinateSystem, Orbit};
+use crate::tle::Tle;
+use nalgebra::{Vector3, Vector6};
+use serde::{Deserialize, Serialize};
 
-#[derive(PartialEq, Debug)]
+/// Number of seconds in a day, used to map `Mission::epoch` (seconds past J2000.0)
+/// to the Julian days expected by `frames::eci_to_ecef`.
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+#[derive(PartialEq, Debug, Serialize, Deserialize)]
 pub struct Mission {
     pub orbit: Orbit,
     pub epoch: f64,
     pub duration: f64,
+    /// Fixed step size, in seconds, used by fixed-step integrators such as `OdeSolver::RungeKutta4`,
+    /// and to sample output times when `propagation_mode` is `PropagationMode::Sgp4`.
+    pub step_size: f64,
+    pub propagation_mode: PropagationMode,
+    /// The two-line element set to propagate when `propagation_mode` is `PropagationMode::Sgp4`.
+    /// Ignored otherwise.
+    pub tle: Option<Tle>,
 }
 
 impl Default for Mission {
@@ -13,16 +28,71 @@ impl Default for Mission {
             orbit: Orbit::default(),
             epoch: 0.0,
             duration: 0.0,
+            step_size: 1.0,
+            propagation_mode: PropagationMode::NumericalIntegration,
+            tle: None,
+        }
+    }
+}
+
+impl Mission {
+    ///! Expresses an Earth-Centered Inertial state at `self.epoch` in the requested
+    ///! `target` frame, rotating into Earth-Centered Earth-Fixed via `frames::eci_to_ecef`
+    ///! when needed. `EarthCenteredInertial` is returned unchanged.
+    pub fn to_frame(
+        &self,
+        target: CoordinateSystem,
+        position: Vector3<f64>,
+        velocity: Vector3<f64>,
+    ) -> (Vector3<f64>, Vector3<f64>) {
+        match target {
+            CoordinateSystem::EarthCenteredInertial => (position, velocity),
+            CoordinateSystem::EarthCenteredEarthFixed => {
+                let state = Vector6::new(
+                    position[0],
+                    position[1],
+                    position[2],
+                    velocity[0],
+                    velocity[1],
+                    velocity[2],
+                );
+                let ecef = eci_to_ecef(state, self.epoch / SECONDS_PER_DAY);
+                (
+                    Vector3::new(ecef[0], ecef[1], ecef[2]),
+                    Vector3::new(ecef[3], ecef[4], ecef[5]),
+                )
+            }
         }
     }
+
+    ///! Parses a full `Mission` definition - elements, chosen solver, epoch, and frame -
+    ///! from a JSON string, e.g. one loaded from a scenario config file.
+    pub fn from_json(json: &str) -> Result<Mission, String> {
+        serde_json::from_str(json).map_err(|err| format!("invalid Mission JSON: {}", err))
+    }
+
+    ///! Serializes this `Mission` to a JSON string for storage in a scenario config file.
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string(self).map_err(|err| format!("failed to serialize Mission: {}", err))
+    }
+}
+
+/// Selects how `propagator::propagate` advances a `Mission`.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub enum PropagationMode {
+    /// Integrate the equations of motion with `orbit.ode_solver`.
+    NumericalIntegration,
+    /// Propagate `mission.tle` analytically via SGP4-style mean-element propagation.
+    Sgp4,
 }
 
 #[cfg(test)]
 mod mission_tests {
     use super::*;
     use crate::bodies::CentralBody;
-    use crate::orbit::{CoordinateSystem, KeplerElements};
+    use crate::orbit::{ForceModel, KeplerElements};
     use crate::propagator::OdeSolver;
+    use nalgebra::Vector3;
 
     #[test]
     fn test_mission_sets_default() {
@@ -39,11 +109,78 @@ mod mission_tests {
                 central_body: CentralBody::EARTH,
                 coordinate_system: CoordinateSystem::EarthCenteredInertial,
                 ode_solver: OdeSolver::RungeKutta4,
+                force_model: ForceModel::TwoBody,
             },
             epoch: 0.0,
             duration: 0.0,
+            step_size: 1.0,
+            propagation_mode: PropagationMode::NumericalIntegration,
+            tle: None,
         };
         let actual_elements = Mission::default();
         assert_eq!(actual_elements, expected_elements)
     }
+
+    #[test]
+    fn test_mission_round_trips_through_json() {
+        let mission = Mission::default();
+
+        let json = serde_json::to_string(&mission).unwrap();
+        let recovered: Mission = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(recovered, mission);
+    }
+
+    #[test]
+    fn test_to_json_round_trips_through_from_json() {
+        let mission = Mission::default();
+
+        let json = mission.to_json().unwrap();
+        let recovered = Mission::from_json(&json).unwrap();
+
+        assert_eq!(recovered, mission);
+    }
+
+    #[test]
+    fn test_from_json_rejects_malformed_input() {
+        assert!(Mission::from_json("not json").is_err());
+    }
+
+    #[test]
+    fn test_to_frame_is_identity_for_eci() {
+        let mission = Mission::default();
+        let position = Vector3::new(7000000.0, 0.0, 0.0);
+        let velocity = Vector3::new(0.0, 7500.0, 0.0);
+
+        let (out_position, out_velocity) =
+            mission.to_frame(CoordinateSystem::EarthCenteredInertial, position, velocity);
+
+        assert_eq!(out_position, position);
+        assert_eq!(out_velocity, velocity);
+    }
+
+    #[test]
+    fn test_to_frame_to_ecef_preserves_position_magnitude() {
+        let mission = Mission {
+            epoch: 12345.0,
+            ..Mission::default()
+        };
+        let position = Vector3::new(7000000.0, 0.0, 0.0);
+        let velocity = Vector3::new(0.0, 7500.0, 0.0);
+
+        let (out_position, out_velocity) = mission.to_frame(
+            CoordinateSystem::EarthCenteredEarthFixed,
+            position,
+            velocity,
+        );
+
+        assert_relatively_eq(out_position.norm(), position.norm(), 1e-6);
+        assert_ne!(out_position, position);
+        assert_ne!(out_velocity, velocity);
+    }
+
+    fn assert_relatively_eq(num_one: f64, num_two: f64, epsilon: f64) {
+        let diff = (num_two - num_one).abs();
+        assert!(diff <= epsilon);
+    }
 }
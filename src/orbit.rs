@@ -1,16 +1,36 @@
 use crate::bodies::CentralBody;
 use crate::propagator::OdeSolver;
 use nalgebra::Vector3;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::f64::consts::PI;
 use std::fmt;
 use std::fmt::Formatter;
 use std::str::FromStr;
 
-#[derive(PartialEq, Debug)]
+/// Elements are treated as circular/equatorial when their defining vector's norm
+/// falls below this threshold, to avoid dividing by a near-zero magnitude.
+const SINGULARITY_TOLERANCE: f64 = 1e-10;
+
+/// Classifies a `KeplerElements` set by the conic section its eccentricity describes.
+#[derive(Debug, PartialEq)]
+pub enum OrbitType {
+    /// Bound orbit, `e < 1`.
+    Elliptical,
+    /// Escape trajectory at exactly the local escape velocity, `e == 1` (within
+    /// `SINGULARITY_TOLERANCE`). `semi_major_axis` is undefined for this case and is
+    /// instead interpreted as the periapsis radius.
+    Parabolic,
+    /// Escape trajectory, `e > 1`. `semi_major_axis` is negative.
+    Hyperbolic,
+}
+
+#[derive(PartialEq, Debug, Serialize, Deserialize)]
 pub struct Orbit {
     pub kepler_elements: KeplerElements,
     pub central_body: CentralBody,
     pub coordinate_system: CoordinateSystem,
     pub ode_solver: OdeSolver,
+    pub force_model: ForceModel,
 }
 
 impl Default for Orbit {
@@ -20,10 +40,61 @@ impl Default for Orbit {
             central_body: CentralBody::EARTH,
             coordinate_system: CoordinateSystem::EarthCenteredInertial,
             ode_solver: OdeSolver::RungeKutta4,
+            force_model: ForceModel::TwoBody,
         }
     }
 }
 
+/// Selects which perturbations are included in the equations of motion used by the propagator.
+#[derive(Debug, PartialEq)]
+pub enum ForceModel {
+    /// Pure point-mass (Keplerian) gravity.
+    TwoBody,
+    /// Point-mass gravity plus the J2 zonal-harmonic oblateness correction.
+    J2,
+}
+
+impl fmt::Display for ForceModel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ForceModel::TwoBody => write!(f, "TwoBody"),
+            ForceModel::J2 => write!(f, "J2"),
+        }
+    }
+}
+
+impl FromStr for ForceModel {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<ForceModel, Self::Err> {
+        match input {
+            "TwoBody" => Ok(ForceModel::TwoBody),
+            "J2" => Ok(ForceModel::J2),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Serialize for ForceModel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ForceModel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        ForceModel::from_str(&value)
+            .map_err(|_| serde::de::Error::custom(format!("invalid ForceModel: {}", value)))
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum CoordinateSystem {
     EarthCenteredInertial,
@@ -51,9 +122,29 @@ impl FromStr for CoordinateSystem {
     }
 }
 
+impl Serialize for CoordinateSystem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CoordinateSystem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        CoordinateSystem::from_str(&value)
+            .map_err(|_| serde::de::Error::custom(format!("invalid CoordinateSystem: {}", value)))
+    }
+}
+
 ///! **Kepler Elements**
 ///! Struct which defines the basic Kepler elements
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Serialize, Deserialize)]
 pub struct KeplerElements {
     pub semi_major_axis: f64,             // in meters
     pub eccentricity: f64,                // dimensionless
@@ -77,10 +168,30 @@ impl Default for KeplerElements {
 }
 
 impl KeplerElements {
-    ///! Convert KeplerElements to position and velocity vectors
+    ///! Classifies this element set by eccentricity: `Elliptical` (e < 1), `Parabolic`
+    ///! (e == 1, within `SINGULARITY_TOLERANCE`), or `Hyperbolic` (e > 1).
+    pub fn classify(&self) -> OrbitType {
+        if (self.eccentricity - 1.0).abs() < SINGULARITY_TOLERANCE {
+            OrbitType::Parabolic
+        } else if self.eccentricity < 1.0 {
+            OrbitType::Elliptical
+        } else {
+            OrbitType::Hyperbolic
+        }
+    }
+
+    ///! Convert KeplerElements to position and velocity vectors. Valid for elliptical and
+    ///! hyperbolic orbits, where `a*(1-e^2)` gives the semi-latus rectum directly (`a` is
+    ///! negative for hyperbolic orbits); for parabolic orbits `semi_major_axis` is instead
+    ///! interpreted as the periapsis radius `r_p`, giving semi-latus rectum `2*r_p`.
     pub fn to_state_vector(&self, mu: f64) -> (Vector3<f64>, Vector3<f64>) {
         //Calculate the Semi-latus rectum for the orbit:
-        let semi_latus_rectum = self.semi_major_axis * (1.0 - self.eccentricity.powi(2));
+        let semi_latus_rectum = match self.classify() {
+            OrbitType::Parabolic => 2.0 * self.semi_major_axis,
+            OrbitType::Elliptical | OrbitType::Hyperbolic => {
+                self.semi_major_axis * (1.0 - self.eccentricity.powi(2))
+            }
+        };
 
         // Calculate the position and velocity in the orbital plane:
         let r = semi_latus_rectum / (1.0 + self.eccentricity * self.true_anomaly.cos());
@@ -100,7 +211,7 @@ impl KeplerElements {
         );
 
         // Calculate the specific angular momentum vector
-        let h = (mu * self.semi_major_axis * (1.0 - self.eccentricity.powi(2))).sqrt();
+        let h = (mu * semi_latus_rectum).sqrt();
 
         let velocity = Vector3::new(
             ((position[0] * h * self.eccentricity) / (r * semi_latus_rectum))
@@ -117,13 +228,315 @@ impl KeplerElements {
                         - self.longitude_of_ascending_node.cos()
                             * arg_of_lat.cos()
                             * self.inclination.cos()),
-            ((position[1] * h * self.eccentricity) / (r * semi_latus_rectum))
+            ((position[2] * h * self.eccentricity) / (r * semi_latus_rectum))
                 * self.true_anomaly.sin()
                 + (h / r) * self.inclination.sin() * arg_of_lat.cos(),
         );
 
         (position, velocity)
     }
+
+    ///! Recover classical Kepler elements from a Cartesian state vector.
+    pub fn from_state_vector(position: Vector3<f64>, velocity: Vector3<f64>, mu: f64) -> KeplerElements {
+        let r = position.norm();
+        let v = velocity.norm();
+        let r_dot_v = position.dot(&velocity);
+
+        // Specific angular momentum and node vector.
+        let h = position.cross(&velocity);
+        let h_norm = h.norm();
+        let n = Vector3::new(0.0, 0.0, 1.0).cross(&h);
+        let n_norm = n.norm();
+
+        // Eccentricity vector.
+        let e_vec = ((v.powi(2) - mu / r) * position - r_dot_v * velocity) / mu;
+        let eccentricity = e_vec.norm();
+
+        let semi_major_axis = 1.0 / (2.0 / r - v.powi(2) / mu);
+        let inclination = (h[2] / h_norm).acos();
+
+        let is_circular = eccentricity < SINGULARITY_TOLERANCE;
+        let is_equatorial = n_norm < SINGULARITY_TOLERANCE;
+
+        let longitude_of_ascending_node = if is_equatorial {
+            0.0
+        } else {
+            let raan = (n[0] / n_norm).clamp(-1.0, 1.0).acos();
+            if n[1] < 0.0 {
+                2.0 * PI - raan
+            } else {
+                raan
+            }
+        };
+
+        let argument_of_periapsis = if is_circular {
+            0.0
+        } else if is_equatorial {
+            // Equatorial, eccentric: measure from the x-axis instead of the node vector.
+            let arg = (e_vec[0] / eccentricity).clamp(-1.0, 1.0).acos();
+            if e_vec[1] < 0.0 {
+                2.0 * PI - arg
+            } else {
+                arg
+            }
+        } else {
+            let arg = (n.dot(&e_vec) / (n_norm * eccentricity))
+                .clamp(-1.0, 1.0)
+                .acos();
+            if e_vec[2] < 0.0 {
+                2.0 * PI - arg
+            } else {
+                arg
+            }
+        };
+
+        let true_anomaly = if is_circular && is_equatorial {
+            // Circular, equatorial: true longitude measured from the x-axis.
+            let nu = (position[0] / r).clamp(-1.0, 1.0).acos();
+            if position[1] < 0.0 {
+                2.0 * PI - nu
+            } else {
+                nu
+            }
+        } else if is_circular {
+            // Circular, inclined: argument of latitude measured from the node vector.
+            let nu = (n.dot(&position) / (n_norm * r)).clamp(-1.0, 1.0).acos();
+            if position[2] < 0.0 {
+                2.0 * PI - nu
+            } else {
+                nu
+            }
+        } else {
+            let nu = (e_vec.dot(&position) / (eccentricity * r))
+                .clamp(-1.0, 1.0)
+                .acos();
+            if r_dot_v < 0.0 {
+                2.0 * PI - nu
+            } else {
+                nu
+            }
+        };
+
+        KeplerElements {
+            semi_major_axis,
+            eccentricity,
+            inclination,
+            longitude_of_ascending_node,
+            argument_of_periapsis,
+            true_anomaly,
+        }
+    }
+
+    ///! Build `KeplerElements` from a mean-anomaly element set (e.g. a TLE or JPL table),
+    ///! converting the mean anomaly to true anomaly via `true_anomaly_from_mean_anomaly`.
+    pub fn from_mean_anomaly(
+        semi_major_axis: f64,
+        eccentricity: f64,
+        inclination: f64,
+        longitude_of_ascending_node: f64,
+        argument_of_periapsis: f64,
+        mean_anomaly: f64,
+    ) -> KeplerElements {
+        KeplerElements {
+            semi_major_axis,
+            eccentricity,
+            inclination,
+            longitude_of_ascending_node,
+            argument_of_periapsis,
+            true_anomaly: true_anomaly_from_mean_anomaly(mean_anomaly, eccentricity),
+        }
+    }
+
+    ///! Analytically advance this element set by `dt` seconds of two-body motion under
+    ///! gravitational parameter `mu`, returning the element set at the new epoch. For
+    ///! `OrbitType::Elliptical`, the current true anomaly is converted to mean anomaly,
+    ///! advanced by the mean motion, and converted back to true anomaly by solving Kepler's
+    ///! equation via `true_anomaly_from_mean_anomaly`. For `OrbitType::Hyperbolic`, the same
+    ///! steps are carried out with the hyperbolic anomaly and the hyperbolic Kepler equation
+    ///! via `true_anomaly_from_hyperbolic_mean_anomaly`.
+    pub fn propagate(&self, mu: f64, dt: f64) -> KeplerElements {
+        let true_anomaly = match self.classify() {
+            OrbitType::Hyperbolic => {
+                let hyperbolic_anomaly = 2.0
+                    * (((self.eccentricity - 1.0) / (self.eccentricity + 1.0)).sqrt()
+                        * (self.true_anomaly / 2.0).tan())
+                    .atanh();
+                let mean_anomaly =
+                    self.eccentricity * hyperbolic_anomaly.sinh() - hyperbolic_anomaly;
+
+                let mean_motion = (mu / self.semi_major_axis.abs().powi(3)).sqrt();
+                let new_mean_anomaly = mean_anomaly + mean_motion * dt;
+
+                true_anomaly_from_hyperbolic_mean_anomaly(new_mean_anomaly, self.eccentricity)
+            }
+            OrbitType::Elliptical | OrbitType::Parabolic => {
+                let sin_eccentric_anomaly =
+                    (1.0 - self.eccentricity.powi(2)).sqrt() * self.true_anomaly.sin();
+                let eccentric_anomaly =
+                    sin_eccentric_anomaly.atan2(self.eccentricity + self.true_anomaly.cos());
+                let mean_anomaly = eccentric_anomaly - self.eccentricity * eccentric_anomaly.sin();
+
+                let mean_motion = (mu / self.semi_major_axis.powi(3)).sqrt();
+                let new_mean_anomaly = (mean_anomaly + mean_motion * dt).rem_euclid(2.0 * PI);
+
+                true_anomaly_from_mean_anomaly(new_mean_anomaly, self.eccentricity)
+            }
+        };
+
+        KeplerElements {
+            semi_major_axis: self.semi_major_axis,
+            eccentricity: self.eccentricity,
+            inclination: self.inclination,
+            longitude_of_ascending_node: self.longitude_of_ascending_node,
+            argument_of_periapsis: self.argument_of_periapsis,
+            true_anomaly,
+        }
+    }
+
+    ///! Orbital period, in seconds: `2*pi*sqrt(a^3/mu)`.
+    pub fn period(&self, mu: f64) -> f64 {
+        2.0 * PI * (self.semi_major_axis.powi(3) / mu).sqrt()
+    }
+
+    ///! Periapsis radius, in meters: `a*(1-e)`.
+    pub fn periapsis_radius(&self) -> f64 {
+        self.semi_major_axis * (1.0 - self.eccentricity)
+    }
+
+    ///! Apoapsis radius, in meters: `a*(1+e)`.
+    pub fn apoapsis_radius(&self) -> f64 {
+        self.semi_major_axis * (1.0 + self.eccentricity)
+    }
+
+    ///! Specific orbital energy, in J/kg: `-mu/(2*a)`.
+    pub fn specific_energy(&self, mu: f64) -> f64 {
+        -mu / (2.0 * self.semi_major_axis)
+    }
+
+    ///! Mean motion, in radians/second: `sqrt(mu/a^3)`.
+    pub fn mean_motion(&self, mu: f64) -> f64 {
+        (mu / self.semi_major_axis.powi(3)).sqrt()
+    }
+}
+
+///! Fluent builder for `KeplerElements`. The eccentricity and inclination setters validate
+///! their input and return a `Result`; the other setters accept any value.
+#[derive(Debug, Default)]
+pub struct KeplerElementsBuilder {
+    semi_major_axis: f64,
+    eccentricity: f64,
+    inclination: f64,
+    longitude_of_ascending_node: f64,
+    argument_of_periapsis: f64,
+    true_anomaly: f64,
+}
+
+impl KeplerElementsBuilder {
+    ///! Starts a new builder with all elements defaulted to zero.
+    pub fn new() -> KeplerElementsBuilder {
+        KeplerElementsBuilder::default()
+    }
+
+    ///! Sets the semi-major axis, in meters.
+    pub fn semi_major_axis(mut self, semi_major_axis: f64) -> KeplerElementsBuilder {
+        self.semi_major_axis = semi_major_axis;
+        self
+    }
+
+    ///! Sets the eccentricity. Errs if `eccentricity < 0`.
+    pub fn eccentricity(mut self, eccentricity: f64) -> Result<KeplerElementsBuilder, String> {
+        if eccentricity < 0.0 {
+            return Err(format!("eccentricity must be >= 0, got {}", eccentricity));
+        }
+        self.eccentricity = eccentricity;
+        Ok(self)
+    }
+
+    ///! Sets the inclination, in radians. Errs if outside `[0, pi]`.
+    pub fn inclination(mut self, inclination: f64) -> Result<KeplerElementsBuilder, String> {
+        if !(0.0..=PI).contains(&inclination) {
+            return Err(format!(
+                "inclination must be in [0, pi], got {}",
+                inclination
+            ));
+        }
+        self.inclination = inclination;
+        Ok(self)
+    }
+
+    ///! Sets the longitude of the ascending node (RAAN), in radians.
+    pub fn longitude_of_ascending_node(
+        mut self,
+        longitude_of_ascending_node: f64,
+    ) -> KeplerElementsBuilder {
+        self.longitude_of_ascending_node = longitude_of_ascending_node;
+        self
+    }
+
+    ///! Sets the argument of periapsis, in radians.
+    pub fn argument_of_periapsis(mut self, argument_of_periapsis: f64) -> KeplerElementsBuilder {
+        self.argument_of_periapsis = argument_of_periapsis;
+        self
+    }
+
+    ///! Sets the true anomaly, in radians.
+    pub fn true_anomaly(mut self, true_anomaly: f64) -> KeplerElementsBuilder {
+        self.true_anomaly = true_anomaly;
+        self
+    }
+
+    ///! Builds the validated `KeplerElements`.
+    pub fn build(self) -> KeplerElements {
+        KeplerElements {
+            semi_major_axis: self.semi_major_axis,
+            eccentricity: self.eccentricity,
+            inclination: self.inclination,
+            longitude_of_ascending_node: self.longitude_of_ascending_node,
+            argument_of_periapsis: self.argument_of_periapsis,
+            true_anomaly: self.true_anomaly,
+        }
+    }
+}
+
+/// Maximum Newton-Raphson iterations allowed when solving Kepler's equation.
+const MAX_KEPLER_ITERATIONS: u32 = 100;
+/// Convergence tolerance, in radians, for the Newton-Raphson update to the eccentric anomaly.
+const KEPLER_TOLERANCE: f64 = 1e-12;
+
+///! Converts a mean anomaly to true anomaly by solving Kepler's equation
+///! `M = E - e*sin(E)` for the eccentric anomaly `E` via Newton-Raphson, starting
+///! from `E0 = M`, then mapping `E` to true anomaly.
+pub fn true_anomaly_from_mean_anomaly(mean_anomaly: f64, eccentricity: f64) -> f64 {
+    let mut eccentric_anomaly = mean_anomaly;
+    for _ in 0..MAX_KEPLER_ITERATIONS {
+        let delta = (eccentric_anomaly - eccentricity * eccentric_anomaly.sin() - mean_anomaly)
+            / (1.0 - eccentricity * eccentric_anomaly.cos());
+        eccentric_anomaly -= delta;
+        if delta.abs() < KEPLER_TOLERANCE {
+            break;
+        }
+    }
+
+    2.0 * ((1.0 + eccentricity).sqrt() * (eccentric_anomaly / 2.0).sin())
+        .atan2((1.0 - eccentricity).sqrt() * (eccentric_anomaly / 2.0).cos())
+}
+
+///! Converts a hyperbolic mean anomaly to true anomaly by solving the hyperbolic Kepler
+///! equation `M = e*sinh(H) - H` for the hyperbolic anomaly `H` via Newton-Raphson, starting
+///! from `H0 = M`, then mapping `H` to true anomaly via `tan(nu/2) = sqrt((e+1)/(e-1))*tanh(H/2)`.
+pub fn true_anomaly_from_hyperbolic_mean_anomaly(mean_anomaly: f64, eccentricity: f64) -> f64 {
+    let mut hyperbolic_anomaly = mean_anomaly;
+    for _ in 0..MAX_KEPLER_ITERATIONS {
+        let delta = (eccentricity * hyperbolic_anomaly.sinh() - hyperbolic_anomaly - mean_anomaly)
+            / (eccentricity * hyperbolic_anomaly.cosh() - 1.0);
+        hyperbolic_anomaly -= delta;
+        if delta.abs() < KEPLER_TOLERANCE {
+            break;
+        }
+    }
+
+    2.0 * (((eccentricity + 1.0) / (eccentricity - 1.0)).sqrt() * (hyperbolic_anomaly / 2.0).tanh())
+        .atan()
 }
 
 #[cfg(test)]
@@ -145,6 +558,7 @@ mod core_tests {
             central_body: CentralBody::EARTH,
             coordinate_system: CoordinateSystem::EarthCenteredInertial,
             ode_solver: OdeSolver::RungeKutta4,
+            force_model: ForceModel::TwoBody,
         };
         let actual_elements = Orbit::default();
         assert_eq!(actual_elements, expected_elements)
@@ -211,6 +625,156 @@ mod core_tests {
         assert!(diff <= epsilon);
     }
 
+    #[test]
+    fn test_kepler_elements_round_trips_through_state_vector() {
+        let eps_angle = 1e-6;
+        let kepler_elements = KeplerElements {
+            semi_major_axis: 6.791301224674748E+06,
+            eccentricity: 8.510618198049622E-04,
+            inclination: 4.949314343620572E+01 * PI / 180.0,
+            longitude_of_ascending_node: 9.440099680297747E+01 * PI / 180.0,
+            argument_of_periapsis: 8.122131421322101E+01 * PI / 180.0,
+            true_anomaly: 3.244321752988205E+02 * PI / 180.0,
+        };
+
+        let mu = 398600.4418e9;
+
+        let (position, velocity) = kepler_elements.to_state_vector(mu);
+        let recovered = KeplerElements::from_state_vector(position, velocity, mu);
+
+        assert_relatively_eq(
+            recovered.semi_major_axis,
+            kepler_elements.semi_major_axis,
+            1.0,
+        );
+        assert_relatively_eq(recovered.eccentricity, kepler_elements.eccentricity, 1e-8);
+        assert_relatively_eq(recovered.inclination, kepler_elements.inclination, eps_angle);
+        assert_relatively_eq(
+            recovered.longitude_of_ascending_node,
+            kepler_elements.longitude_of_ascending_node,
+            eps_angle,
+        );
+        assert_relatively_eq(
+            recovered.argument_of_periapsis,
+            kepler_elements.argument_of_periapsis,
+            eps_angle,
+        );
+        assert_relatively_eq(
+            recovered.true_anomaly,
+            kepler_elements.true_anomaly,
+            eps_angle,
+        );
+    }
+
+    #[test]
+    fn test_kepler_elements_from_state_vector_handles_circular_equatorial_orbit() {
+        let kepler_elements = KeplerElements {
+            semi_major_axis: 7000000.0,
+            eccentricity: 0.0,
+            inclination: 0.0,
+            longitude_of_ascending_node: 0.0,
+            argument_of_periapsis: 0.0,
+            true_anomaly: 1.2,
+        };
+
+        let mu = 398600.4418e9;
+
+        let (position, velocity) = kepler_elements.to_state_vector(mu);
+        let recovered = KeplerElements::from_state_vector(position, velocity, mu);
+
+        assert_relatively_eq(recovered.semi_major_axis, kepler_elements.semi_major_axis, 1.0);
+        assert_relatively_eq(recovered.eccentricity, 0.0, 1e-8);
+        assert_relatively_eq(recovered.inclination, 0.0, 1e-8);
+    }
+
+    #[test]
+    fn test_kepler_elements_from_state_vector_round_trips_on_the_inbound_leg() {
+        // Past apoapsis, descending toward periapsis: exercises the n_y, e_z, and r_dot_v < 0
+        // quadrant corrections in the RAAN, argument of periapsis, and true anomaly branches.
+        let kepler_elements = KeplerElements {
+            semi_major_axis: 7000000.0,
+            eccentricity: 0.2,
+            inclination: 0.9,
+            longitude_of_ascending_node: 4.5,
+            argument_of_periapsis: 3.8,
+            true_anomaly: 4.2,
+        };
+
+        let mu = 398600.4418e9;
+
+        let (position, velocity) = kepler_elements.to_state_vector(mu);
+        let recovered = KeplerElements::from_state_vector(position, velocity, mu);
+
+        assert_relatively_eq(recovered.semi_major_axis, kepler_elements.semi_major_axis, 1.0);
+        assert_relatively_eq(recovered.eccentricity, kepler_elements.eccentricity, 1e-8);
+        assert_relatively_eq(recovered.inclination, kepler_elements.inclination, 1e-6);
+        assert_relatively_eq(
+            recovered.longitude_of_ascending_node,
+            kepler_elements.longitude_of_ascending_node,
+            1e-6,
+        );
+        assert_relatively_eq(
+            recovered.argument_of_periapsis,
+            kepler_elements.argument_of_periapsis,
+            1e-6,
+        );
+        assert_relatively_eq(recovered.true_anomaly, kepler_elements.true_anomaly, 1e-6);
+    }
+
+    #[test]
+    fn test_force_model_enum_supports_to_string() {
+        assert_eq!(ForceModel::TwoBody.to_string(), "TwoBody");
+        assert_eq!(ForceModel::J2.to_string(), "J2");
+    }
+
+    #[test]
+    fn test_force_model_enum_supports_from_str() {
+        assert_eq!(ForceModel::from_str("TwoBody").unwrap(), ForceModel::TwoBody);
+        assert_eq!(ForceModel::from_str("J2").unwrap(), ForceModel::J2);
+    }
+
+    #[test]
+    fn test_true_anomaly_from_mean_anomaly_circular_orbit_is_identity() {
+        let mean_anomaly = 1.0;
+        let true_anomaly = true_anomaly_from_mean_anomaly(mean_anomaly, 0.0);
+        assert_relatively_eq(true_anomaly, mean_anomaly, 1e-12);
+    }
+
+    #[test]
+    fn test_true_anomaly_from_mean_anomaly_satisfies_keplers_equation() {
+        let mean_anomaly = 0.7;
+        let eccentricity = 0.2;
+        let true_anomaly = true_anomaly_from_mean_anomaly(mean_anomaly, eccentricity);
+
+        // Invert true anomaly back to eccentric anomaly and check Kepler's equation holds.
+        let eccentric_anomaly = 2.0
+            * ((1.0 - eccentricity).sqrt() * (true_anomaly / 2.0).sin())
+                .atan2((1.0 + eccentricity).sqrt() * (true_anomaly / 2.0).cos());
+        let recovered_mean_anomaly =
+            eccentric_anomaly - eccentricity * eccentric_anomaly.sin();
+
+        assert_relatively_eq(recovered_mean_anomaly, mean_anomaly, 1e-10);
+    }
+
+    #[test]
+    fn test_kepler_elements_from_mean_anomaly() {
+        let mean_anomaly = 0.5;
+        let eccentricity = 0.1;
+        let elements = KeplerElements::from_mean_anomaly(
+            7000000.0,
+            eccentricity,
+            0.9,
+            1.1,
+            0.3,
+            mean_anomaly,
+        );
+
+        assert_eq!(
+            elements.true_anomaly,
+            true_anomaly_from_mean_anomaly(mean_anomaly, eccentricity)
+        );
+    }
+
     #[test]
     fn test_coordinate_system_enum_supports_to_string() {
         assert_eq!(
@@ -234,4 +798,214 @@ mod core_tests {
             CoordinateSystem::EarthCenteredInertial
         );
     }
+
+    #[test]
+    fn test_propagate_a_full_period_returns_to_the_same_true_anomaly() {
+        let kepler_elements = KeplerElements {
+            semi_major_axis: 7000000.0,
+            eccentricity: 0.1,
+            inclination: 0.9,
+            longitude_of_ascending_node: 1.1,
+            argument_of_periapsis: 0.3,
+            true_anomaly: 0.5,
+        };
+        let mu = 398600.4418e9;
+        let period = 2.0 * PI * (kepler_elements.semi_major_axis.powi(3) / mu).sqrt();
+
+        let propagated = kepler_elements.propagate(mu, period);
+
+        assert_relatively_eq(propagated.true_anomaly, kepler_elements.true_anomaly, 1e-8);
+        assert_eq!(propagated.semi_major_axis, kepler_elements.semi_major_axis);
+        assert_eq!(propagated.eccentricity, kepler_elements.eccentricity);
+    }
+
+    #[test]
+    fn test_propagate_advances_mean_anomaly_for_circular_orbit() {
+        let kepler_elements = KeplerElements {
+            semi_major_axis: 7000000.0,
+            eccentricity: 0.0,
+            inclination: 0.0,
+            longitude_of_ascending_node: 0.0,
+            argument_of_periapsis: 0.0,
+            true_anomaly: 0.0,
+        };
+        let mu = 398600.4418e9;
+        let mean_motion = (mu / kepler_elements.semi_major_axis.powi(3)).sqrt();
+        let dt = 100.0;
+
+        let propagated = kepler_elements.propagate(mu, dt);
+
+        // For a circular orbit, true anomaly advances in lockstep with mean anomaly.
+        assert_relatively_eq(propagated.true_anomaly, mean_motion * dt, 1e-8);
+    }
+
+    #[test]
+    fn test_derived_orbital_quantities() {
+        let kepler_elements = KeplerElements {
+            semi_major_axis: 7000000.0,
+            eccentricity: 0.1,
+            ..Default::default()
+        };
+        let mu = 398600.4418e9;
+
+        assert_relatively_eq(
+            kepler_elements.period(mu),
+            2.0 * PI * (kepler_elements.semi_major_axis.powi(3) / mu).sqrt(),
+            1e-6,
+        );
+        assert_relatively_eq(kepler_elements.periapsis_radius(), 6300000.0, 1e-6);
+        assert_relatively_eq(kepler_elements.apoapsis_radius(), 7700000.0, 1e-6);
+        assert_relatively_eq(
+            kepler_elements.specific_energy(mu),
+            -mu / (2.0 * kepler_elements.semi_major_axis),
+            1e-6,
+        );
+        assert_relatively_eq(
+            kepler_elements.mean_motion(mu),
+            (mu / kepler_elements.semi_major_axis.powi(3)).sqrt(),
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn test_kepler_elements_builder_builds_the_requested_elements() {
+        let kepler_elements = KeplerElementsBuilder::new()
+            .semi_major_axis(7000000.0)
+            .eccentricity(0.1)
+            .unwrap()
+            .inclination(0.9)
+            .unwrap()
+            .longitude_of_ascending_node(1.1)
+            .argument_of_periapsis(0.3)
+            .true_anomaly(0.5)
+            .build();
+
+        assert_eq!(
+            kepler_elements,
+            KeplerElements {
+                semi_major_axis: 7000000.0,
+                eccentricity: 0.1,
+                inclination: 0.9,
+                longitude_of_ascending_node: 1.1,
+                argument_of_periapsis: 0.3,
+                true_anomaly: 0.5,
+            }
+        );
+    }
+
+    #[test]
+    fn test_kepler_elements_builder_rejects_negative_eccentricity() {
+        let result = KeplerElementsBuilder::new().eccentricity(-0.1);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_kepler_elements_builder_rejects_out_of_range_inclination() {
+        let result = KeplerElementsBuilder::new().inclination(-0.1);
+        assert!(result.is_err());
+
+        let result = KeplerElementsBuilder::new().inclination(PI + 0.1);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_classify_identifies_elliptical_parabolic_and_hyperbolic_orbits() {
+        let elliptical = KeplerElements {
+            eccentricity: 0.5,
+            ..Default::default()
+        };
+        let parabolic = KeplerElements {
+            eccentricity: 1.0,
+            ..Default::default()
+        };
+        let hyperbolic = KeplerElements {
+            eccentricity: 1.5,
+            ..Default::default()
+        };
+
+        assert_eq!(elliptical.classify(), OrbitType::Elliptical);
+        assert_eq!(parabolic.classify(), OrbitType::Parabolic);
+        assert_eq!(hyperbolic.classify(), OrbitType::Hyperbolic);
+    }
+
+    #[test]
+    fn test_hyperbolic_state_vector_has_escape_energy() {
+        let kepler_elements = KeplerElements {
+            semi_major_axis: -7000000.0,
+            eccentricity: 1.5,
+            inclination: 0.4,
+            longitude_of_ascending_node: 0.2,
+            argument_of_periapsis: 0.1,
+            true_anomaly: 0.3,
+        };
+        let mu = 398600.4418e9;
+
+        let (position, velocity) = kepler_elements.to_state_vector(mu);
+
+        // Specific orbital energy xi = v^2/2 - mu/r should equal -mu/(2a), which is
+        // positive for a hyperbolic (a < 0) orbit -- the hallmark of an escape trajectory.
+        let specific_energy = velocity.norm_squared() / 2.0 - mu / position.norm();
+        let expected_energy = -mu / (2.0 * kepler_elements.semi_major_axis);
+
+        assert_relatively_eq(specific_energy, expected_energy, 1.0);
+        assert!(specific_energy > 0.0);
+    }
+
+    #[test]
+    fn test_propagate_hyperbolic_orbit_round_trips_via_state_vector() {
+        let kepler_elements = KeplerElements {
+            semi_major_axis: -7000000.0,
+            eccentricity: 1.5,
+            inclination: 0.4,
+            longitude_of_ascending_node: 0.2,
+            argument_of_periapsis: 0.1,
+            true_anomaly: 0.3,
+        };
+        let mu = 398600.4418e9;
+        let dt = 50.0;
+
+        let propagated = kepler_elements.propagate(mu, dt);
+        let (position, velocity) = propagated.to_state_vector(mu);
+        let recovered = KeplerElements::from_state_vector(position, velocity, mu);
+
+        assert_relatively_eq(
+            recovered.semi_major_axis,
+            kepler_elements.semi_major_axis,
+            1.0,
+        );
+        assert_relatively_eq(recovered.eccentricity, kepler_elements.eccentricity, 1e-6);
+        assert_relatively_eq(recovered.true_anomaly, propagated.true_anomaly, 1e-6);
+    }
+
+    #[test]
+    fn test_force_model_round_trips_through_json() {
+        let json = serde_json::to_string(&ForceModel::J2).unwrap();
+        assert_eq!(json, "\"J2\"");
+        let recovered: ForceModel = serde_json::from_str(&json).unwrap();
+        assert_eq!(recovered, ForceModel::J2);
+    }
+
+    #[test]
+    fn test_coordinate_system_round_trips_through_json() {
+        let json = serde_json::to_string(&CoordinateSystem::EarthCenteredEarthFixed).unwrap();
+        assert_eq!(json, "\"EarthCenteredEarthFixed\"");
+        let recovered: CoordinateSystem = serde_json::from_str(&json).unwrap();
+        assert_eq!(recovered, CoordinateSystem::EarthCenteredEarthFixed);
+    }
+
+    #[test]
+    fn test_kepler_elements_round_trips_through_json() {
+        let kepler_elements = KeplerElements {
+            semi_major_axis: 7000000.0,
+            eccentricity: 0.1,
+            inclination: 0.9,
+            longitude_of_ascending_node: 1.1,
+            argument_of_periapsis: 0.3,
+            true_anomaly: 0.5,
+        };
+
+        let json = serde_json::to_string(&kepler_elements).unwrap();
+        let recovered: KeplerElements = serde_json::from_str(&json).unwrap();
+        assert_eq!(recovered, kepler_elements);
+    }
 }
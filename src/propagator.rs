@@ -1,8 +1,13 @@
 use crate::bodies::get_body;
-use crate::mission::Mission;
-use crate::orbit::Orbit;
+use crate::frames::eci_to_ecef;
+use crate::mission::{Mission, PropagationMode};
+use crate::orbit::{CoordinateSystem, ForceModel, Orbit};
+use crate::sgp4::propagate_tle;
+use ode_solvers::dop853::*;
 use ode_solvers::dopri5::*;
+use ode_solvers::rk4::*;
 use ode_solvers::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 use std::fmt::Formatter;
 use std::str::FromStr;
@@ -40,6 +45,26 @@ impl FromStr for OdeSolver {
     }
 }
 
+impl Serialize for OdeSolver {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for OdeSolver {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        OdeSolver::from_str(&value)
+            .map_err(|_| serde::de::Error::custom(format!("invalid OdeSolver: {}", value)))
+    }
+}
+
 impl System<State> for Orbit {
     /**
     Kepler Orbit Equations of motion.
@@ -49,57 +74,156 @@ impl System<State> for Orbit {
        * 'dy' -  The change in the state vector
      */
     fn system(&self, _t: Time, y: &State, dy: &mut State) {
-        let denominator: f64 = (y[0].powf(2.0) + y[1].powf(2.0) + y[2].powf(2.0)).powf(3.0 / 2.0);
+        let r2 = y[0].powf(2.0) + y[1].powf(2.0) + y[2].powf(2.0);
+        let r = r2.sqrt();
+        let r3 = r2 * r;
         let body = get_body(&self.central_body);
+
         dy[0] = y[3];
         dy[1] = y[4];
         dy[2] = y[5];
-        dy[3] = -body.mu * y[0] / denominator;
-        dy[4] = -body.mu * y[1] / denominator;
-        dy[5] = -body.mu * y[2] / denominator;
+
+        match self.force_model {
+            ForceModel::TwoBody => {
+                dy[3] = -body.mu * y[0] / r3;
+                dy[4] = -body.mu * y[1] / r3;
+                dy[5] = -body.mu * y[2] / r3;
+            }
+            ForceModel::J2 => {
+                let k = 1.5 * body.j2 * body.mu * body.radius.powi(2) / r2.powf(5.0 / 2.0);
+                let z2_over_r2 = y[2].powf(2.0) / r2;
+                dy[3] = -body.mu * y[0] / r3 + k * y[0] * (5.0 * z2_over_r2 - 1.0);
+                dy[4] = -body.mu * y[1] / r3 + k * y[1] * (5.0 * z2_over_r2 - 1.0);
+                dy[5] = -body.mu * y[2] / r3 + k * y[2] * (5.0 * z2_over_r2 - 3.0);
+            }
+        }
     }
 }
 
+/// Number of seconds in a day, used to map the propagator's time variable (which
+/// starts at `mission.epoch`, treated as seconds past J2000.0) to Julian days.
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+/// Converts a batch of propagated states into the orbit's configured output frame.
+/// `EarthCenteredInertial` states are returned unchanged; `EarthCenteredEarthFixed`
+/// states are rotated from ECI to ECEF per-sample using that sample's own epoch.
+fn to_output_frame(
+    is_ecef: bool,
+    states: Vec<Vector6<f64>>,
+    times: &[f64],
+) -> Vec<Vector6<f64>> {
+    if !is_ecef {
+        return states;
+    }
+    states
+        .into_iter()
+        .zip(times)
+        .map(|(state, t)| eci_to_ecef(state, t / SECONDS_PER_DAY))
+        .collect()
+}
+
+/// Builds the list of output sample times used by the SGP4 propagation path, running
+/// from `epoch` to `epoch + duration` and spaced by `step_size`.
+fn sample_times(epoch: f64, duration: f64, step_size: f64) -> Vec<f64> {
+    let mut times = Vec::new();
+    let mut t = epoch;
+    while t < epoch + duration {
+        times.push(t);
+        t += step_size;
+    }
+    times.push(epoch + duration);
+    times
+}
+
 /**
 Propagate a state vector for a given time of flight.
+
+Dispatches on `mission.propagation_mode`: `PropagationMode::NumericalIntegration`
+integrates `mission.orbit.ode_solver`'s equations of motion, while
+`PropagationMode::Sgp4` analytically propagates `mission.tle` instead.
  */
 pub fn propagate(mission: Mission) -> Vec<Vector6<f64>> {
+    match mission.propagation_mode {
+        PropagationMode::Sgp4 => {
+            let tle = mission
+                .tle
+                .as_ref()
+                .expect("ERROR: PropagationMode::Sgp4 requires mission.tle to be set.");
+            let body = get_body(&mission.orbit.central_body);
+            let times = sample_times(mission.epoch, mission.duration, mission.step_size);
+            propagate_tle(tle, body.mu, &times)
+        }
+        PropagationMode::NumericalIntegration => propagate_numerically(mission),
+    }
+}
+
+/**
+Propagate a state vector for a given time of flight by numerically integrating
+`mission.orbit.ode_solver`'s equations of motion.
+
+Dispatches on `mission.orbit.ode_solver` so the mission's configured numerical
+method is actually used: `OdeSolver::RungeKutta4` runs a fixed-step `Rk4`
+integrator using `mission.step_size`, while `OdeSolver::DormandPrince5` and
+`OdeSolver::DormandPrince853` run the corresponding adaptive-step integrators
+with the same rtol/atol tolerances. The output is further rotated into ECEF
+when `mission.orbit.coordinate_system` requests it.
+ */
+fn propagate_numerically(mission: Mission) -> Vec<Vector6<f64>> {
     let body = get_body(&mission.orbit.central_body);
     let (position, velocity) = mission.orbit.kepler_elements.to_state_vector(body.mu);
+    let y0 = State::new(
+        position[0],
+        position[1],
+        position[2],
+        velocity[0],
+        velocity[1],
+        velocity[2],
+    );
+    let is_ecef = matches!(
+        mission.orbit.coordinate_system,
+        CoordinateSystem::EarthCenteredEarthFixed
+    );
     let system = mission.orbit;
 
     let rtol: f64 = 1e-6;
     let atol: f64 = 1e-8;
 
-    let mut stepper = Dopri5::new(
-        system,
-        mission.epoch,
-        mission.duration,
-        10.0,
-        State::new(
-            position[0],
-            position[1],
-            position[2],
-            velocity[0],
-            velocity[1],
-            velocity[2],
-        ),
-        rtol,
-        atol,
-    );
-    stepper
-        .integrate()
-        .expect("ERROR: Unable to integrate provided parameters.");
-
-    let y_out = stepper.y_out();
-    y_out.to_vec()
+    match &system.ode_solver {
+        OdeSolver::RungeKutta4 => {
+            let mut stepper = Rk4::new(
+                system,
+                mission.epoch,
+                y0,
+                mission.duration,
+                mission.step_size,
+            );
+            stepper
+                .integrate()
+                .expect("ERROR: Unable to integrate provided parameters.");
+            to_output_frame(is_ecef, stepper.y_out().to_vec(), stepper.x_out())
+        }
+        OdeSolver::DormandPrince5 => {
+            let mut stepper = Dopri5::new(system, mission.epoch, mission.duration, 10.0, y0, rtol, atol);
+            stepper
+                .integrate()
+                .expect("ERROR: Unable to integrate provided parameters.");
+            to_output_frame(is_ecef, stepper.y_out().to_vec(), stepper.x_out())
+        }
+        OdeSolver::DormandPrince853 => {
+            let mut stepper = Dop853::new(system, mission.epoch, mission.duration, 10.0, y0, rtol, atol);
+            stepper
+                .integrate()
+                .expect("ERROR: Unable to integrate provided parameters.");
+            to_output_frame(is_ecef, stepper.y_out().to_vec(), stepper.x_out())
+        }
+    }
 }
 
 #[cfg(test)]
 mod propagator_tests {
     use super::*;
     use crate::bodies::CentralBody;
-    use crate::orbit::{CoordinateSystem, KeplerElements};
+    use crate::orbit::{CoordinateSystem, ForceModel, KeplerElements};
     use std::f64::consts::PI;
 
     #[test]
@@ -119,21 +243,25 @@ mod propagator_tests {
                 central_body: CentralBody::EARTH,
                 coordinate_system: CoordinateSystem::EarthCenteredInertial,
                 ode_solver: OdeSolver::DormandPrince5,
+                force_model: ForceModel::TwoBody,
             },
             epoch: 0.0,
             duration: 60.0*60.0,
+            step_size: 1.0,
+            propagation_mode: PropagationMode::NumericalIntegration,
+            tle: None,
         };
         let result = propagate(mission);
         let final_value = result.last().unwrap();
 
         // TODO: These are inaccurate values that will need to be updated as the model is improved.
         let expected_out_state = [
-            4278239.0,
-            1324790.0,
-            -5111879.0,
-            -1305.0,
-            7494.0,
-            851.0,
+            4279093.0,
+            1320975.0,
+            -5112847.0,
+            -1303.0,
+            7495.0,
+            848.0,
         ];
 
         // Position Vectors
@@ -152,6 +280,165 @@ mod propagator_tests {
         assert!(diff <= epsilon);
     }
 
+    #[test]
+    fn test_should_integrate_with_rk4() {
+        let eps_pos = 200.0;
+        let eps_vel = 10.0;
+        let mission = Mission {
+            orbit: Orbit {
+                kepler_elements: KeplerElements {
+                    semi_major_axis: 6.791301224674748E+06,
+                    eccentricity: 8.510618198049622E-04,
+                    inclination: 4.949314343620572E+01 * PI / 180.0,
+                    longitude_of_ascending_node: 9.440099680297747E+01 * PI / 180.0,
+                    argument_of_periapsis: 8.122131421322101E+01 * PI / 180.0,
+                    true_anomaly: 3.244321752988205E+02 * PI / 180.0,
+                },
+                central_body: CentralBody::EARTH,
+                coordinate_system: CoordinateSystem::EarthCenteredInertial,
+                ode_solver: OdeSolver::RungeKutta4,
+                force_model: ForceModel::TwoBody,
+            },
+            epoch: 0.0,
+            duration: 60.0 * 60.0,
+            step_size: 1.0,
+            propagation_mode: PropagationMode::NumericalIntegration,
+            tle: None,
+        };
+        let result = propagate(mission);
+        let final_value = result.last().unwrap();
+
+        // RK4 is a fixed-step integrator, so it should track the adaptive
+        // Dopri5 result from `test_should_integrate` within the same tolerance.
+        let expected_out_state = [4279093.0, 1320975.0, -5112847.0, -1303.0, 7495.0, 848.0];
+
+        assert_relatively_eq(final_value[0], expected_out_state[0], eps_pos);
+        assert_relatively_eq(final_value[1], expected_out_state[1], eps_pos);
+        assert_relatively_eq(final_value[2], expected_out_state[2], eps_pos);
+
+        assert_relatively_eq(final_value[3], expected_out_state[3], eps_vel);
+        assert_relatively_eq(final_value[4], expected_out_state[4], eps_vel);
+        assert_relatively_eq(final_value[5], expected_out_state[5], eps_vel);
+    }
+
+    #[test]
+    fn test_propagate_sgp4_mode_returns_sampled_states() {
+        use crate::tle::Tle;
+
+        let line1 = "1 25544U 98067A   24045.52849537  .00016717  00000-0  30721-3 0  9991";
+        let line2 = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.49589229439313";
+        let tle = Tle::parse(line1, line2).unwrap();
+
+        let mission = Mission {
+            orbit: Orbit::default(),
+            epoch: 0.0,
+            duration: 120.0,
+            step_size: 60.0,
+            propagation_mode: PropagationMode::Sgp4,
+            tle: Some(tle),
+        };
+
+        let states = propagate(mission);
+
+        assert_eq!(states.len(), 3);
+    }
+
+    #[test]
+    fn test_ecef_coordinate_system_rotates_output_from_eci() {
+        let make_mission = |coordinate_system| Mission {
+            orbit: Orbit {
+                kepler_elements: KeplerElements {
+                    semi_major_axis: 6.791301224674748E+06,
+                    eccentricity: 8.510618198049622E-04,
+                    inclination: 4.949314343620572E+01 * PI / 180.0,
+                    longitude_of_ascending_node: 9.440099680297747E+01 * PI / 180.0,
+                    argument_of_periapsis: 8.122131421322101E+01 * PI / 180.0,
+                    true_anomaly: 3.244321752988205E+02 * PI / 180.0,
+                },
+                central_body: CentralBody::EARTH,
+                coordinate_system,
+                ode_solver: OdeSolver::DormandPrince5,
+                force_model: ForceModel::TwoBody,
+            },
+            epoch: 0.0,
+            duration: 60.0 * 60.0,
+            step_size: 1.0,
+            propagation_mode: PropagationMode::NumericalIntegration,
+            tle: None,
+        };
+
+        let eci_final = *propagate(make_mission(CoordinateSystem::EarthCenteredInertial))
+            .last()
+            .unwrap();
+        let ecef_final = *propagate(make_mission(CoordinateSystem::EarthCenteredEarthFixed))
+            .last()
+            .unwrap();
+
+        // ECI->ECEF is a rigid rotation about z, so position magnitude is preserved
+        // while the components differ from the inertial-frame output.
+        let eci_radius = (eci_final[0].powi(2) + eci_final[1].powi(2)).sqrt();
+        let ecef_radius = (ecef_final[0].powi(2) + ecef_final[1].powi(2)).sqrt();
+        assert!((eci_radius - ecef_radius).abs() < 1e-3);
+        assert!((eci_final[0] - ecef_final[0]).abs() > 1.0);
+    }
+
+    #[test]
+    fn test_j2_force_model_perturbs_from_two_body() {
+        let mission_two_body = Mission {
+            orbit: Orbit {
+                kepler_elements: KeplerElements {
+                    semi_major_axis: 6.791301224674748E+06,
+                    eccentricity: 8.510618198049622E-04,
+                    inclination: 4.949314343620572E+01 * PI / 180.0,
+                    longitude_of_ascending_node: 9.440099680297747E+01 * PI / 180.0,
+                    argument_of_periapsis: 8.122131421322101E+01 * PI / 180.0,
+                    true_anomaly: 3.244321752988205E+02 * PI / 180.0,
+                },
+                central_body: CentralBody::EARTH,
+                coordinate_system: CoordinateSystem::EarthCenteredInertial,
+                ode_solver: OdeSolver::DormandPrince5,
+                force_model: ForceModel::TwoBody,
+            },
+            epoch: 0.0,
+            duration: 60.0 * 60.0,
+            step_size: 1.0,
+            propagation_mode: PropagationMode::NumericalIntegration,
+            tle: None,
+        };
+        let mission_j2 = Mission {
+            orbit: Orbit {
+                kepler_elements: KeplerElements {
+                    semi_major_axis: 6.791301224674748E+06,
+                    eccentricity: 8.510618198049622E-04,
+                    inclination: 4.949314343620572E+01 * PI / 180.0,
+                    longitude_of_ascending_node: 9.440099680297747E+01 * PI / 180.0,
+                    argument_of_periapsis: 8.122131421322101E+01 * PI / 180.0,
+                    true_anomaly: 3.244321752988205E+02 * PI / 180.0,
+                },
+                central_body: CentralBody::EARTH,
+                coordinate_system: CoordinateSystem::EarthCenteredInertial,
+                ode_solver: OdeSolver::DormandPrince5,
+                force_model: ForceModel::J2,
+            },
+            epoch: 0.0,
+            duration: 60.0 * 60.0,
+            step_size: 1.0,
+            propagation_mode: PropagationMode::NumericalIntegration,
+            tle: None,
+        };
+
+        let two_body_final = *propagate(mission_two_body).last().unwrap();
+        let j2_final = *propagate(mission_j2).last().unwrap();
+
+        // The J2 perturbation should measurably shift the final state away
+        // from the pure two-body result over a one-hour propagation.
+        let position_delta = ((j2_final[0] - two_body_final[0]).powi(2)
+            + (j2_final[1] - two_body_final[1]).powi(2)
+            + (j2_final[2] - two_body_final[2]).powi(2))
+        .sqrt();
+        assert!(position_delta > 1.0);
+    }
+
     #[test]
     fn test_ode_solver_enum_supports_to_string() {
         assert_eq!(OdeSolver::RungeKutta4.to_string(), "RungeKutta4");
@@ -174,4 +461,12 @@ mod propagator_tests {
             OdeSolver::DormandPrince853
         );
     }
+
+    #[test]
+    fn test_ode_solver_round_trips_through_json() {
+        let json = serde_json::to_string(&OdeSolver::DormandPrince853).unwrap();
+        assert_eq!(json, "\"DormandPrince853\"");
+        let recovered: OdeSolver = serde_json::from_str(&json).unwrap();
+        assert_eq!(recovered, OdeSolver::DormandPrince853);
+    }
 }
@@ -0,0 +1,83 @@
+use crate::orbit::KeplerElements;
+use crate::tle::Tle;
+use nalgebra::Vector6;
+use std::f64::consts::PI;
+
+/// Propagates a TLE's mean elements forward to each requested time (seconds past
+/// the TLE epoch), returning ECI states in the same `Vec<Vector6<f64>>` shape as
+/// `propagator::propagate`.
+///
+/// This advances the mean anomaly analytically using the TLE's mean motion, with
+/// a first-order secular correction from the `bstar` drag term, then converts the
+/// resulting mean elements to a Cartesian state the same way the two-body
+/// analytic propagator does. It does not model the full SGP4/SDP4 perturbation
+/// set (zonal harmonics, atmospheric drag beyond the secular term, deep-space
+/// resonances) -- it exists to let users propagate catalog objects distributed
+/// as TLEs without needing osculating elements.
+pub fn propagate_tle(tle: &Tle, mu: f64, times: &[f64]) -> Vec<Vector6<f64>> {
+    let mean_motion = tle.mean_motion * 2.0 * PI / 86400.0; // rad/s
+    let drag_rate = tle.bstar * mean_motion;
+    let semi_major_axis = tle.semi_major_axis(mu);
+
+    times
+        .iter()
+        .map(|&t| {
+            let mean_anomaly = tle.mean_anomaly + mean_motion * t + 0.5 * drag_rate * t.powi(2);
+
+            let elements = KeplerElements::from_mean_anomaly(
+                semi_major_axis,
+                tle.eccentricity,
+                tle.inclination,
+                tle.raan,
+                tle.argument_of_perigee,
+                mean_anomaly,
+            );
+            let (position, velocity) = elements.to_state_vector(mu);
+            Vector6::new(
+                position[0],
+                position[1],
+                position[2],
+                velocity[0],
+                velocity[1],
+                velocity[2],
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod sgp4_tests {
+    use super::*;
+
+    const LINE1: &str = "1 25544U 98067A   24045.52849537  .00016717  00000-0  30721-3 0  9991";
+    const LINE2: &str = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.49589229439313";
+
+    #[test]
+    fn test_propagate_tle_returns_one_state_per_time() {
+        let tle = Tle::parse(LINE1, LINE2).unwrap();
+        let mu = 398600.4418e9;
+        let times = [0.0, 60.0, 120.0];
+
+        let states = propagate_tle(&tle, mu, &times);
+
+        assert_eq!(states.len(), times.len());
+    }
+
+    #[test]
+    fn test_propagate_tle_at_epoch_matches_mean_elements() {
+        let tle = Tle::parse(LINE1, LINE2).unwrap();
+        let mu = 398600.4418e9;
+
+        let states = propagate_tle(&tle, mu, &[0.0]);
+        let (expected_position, _) = tle.mean_elements(mu).to_state_vector(mu);
+
+        assert_relatively_eq(states[0][0], expected_position[0], 1e-3);
+        assert_relatively_eq(states[0][1], expected_position[1], 1e-3);
+        assert_relatively_eq(states[0][2], expected_position[2], 1e-3);
+    }
+
+    fn assert_relatively_eq(num_one: f64, num_two: f64, epsilon: f64) {
+        let diff = (num_two - num_one).abs();
+        assert!(diff <= epsilon);
+    }
+}
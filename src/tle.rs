@@ -0,0 +1,149 @@
+use crate::orbit::KeplerElements;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// A parsed two-line element set, carrying the subset of fields needed to
+/// build a mean Keplerian element set for propagation.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Tle {
+    pub catalog_number: u32,
+    pub epoch_year: u32,
+    pub epoch_day: f64,
+    pub inclination: f64,         // radians
+    pub raan: f64,                // radians
+    pub eccentricity: f64,        // dimensionless
+    pub argument_of_perigee: f64, // radians
+    pub mean_anomaly: f64,        // radians
+    pub mean_motion: f64,         // revolutions per day
+    pub bstar: f64,               // drag term, 1/earth radii
+}
+
+impl Tle {
+    ///! Parse a standard NORAD two-line element set (line 1 and line 2, without the
+    ///! optional leading title line).
+    pub fn parse(line1: &str, line2: &str) -> Result<Tle, String> {
+        if line1.len() < 69 || line2.len() < 69 {
+            return Err("TLE lines must be at least 69 characters long".to_string());
+        }
+
+        let catalog_number = parse_field(&line1[2..7])?;
+        let epoch_year = parse_field(&line1[18..20])?;
+        let epoch_day = parse_field(&line1[20..32])?;
+        let bstar = parse_bstar(&line1[53..59], &line1[59..61])?;
+
+        let inclination: f64 = parse_field::<f64>(&line2[8..16])? * PI / 180.0;
+        let raan: f64 = parse_field::<f64>(&line2[17..25])? * PI / 180.0;
+        let eccentricity: f64 = format!("0.{}", line2[26..33].trim())
+            .parse()
+            .map_err(|_| "invalid eccentricity field".to_string())?;
+        let argument_of_perigee: f64 = parse_field::<f64>(&line2[34..42])? * PI / 180.0;
+        let mean_anomaly: f64 = parse_field::<f64>(&line2[43..51])? * PI / 180.0;
+        let mean_motion: f64 = parse_field(&line2[52..63])?;
+
+        Ok(Tle {
+            catalog_number,
+            epoch_year,
+            epoch_day,
+            inclination,
+            raan,
+            eccentricity,
+            argument_of_perigee,
+            mean_anomaly,
+            mean_motion,
+            bstar,
+        })
+    }
+
+    ///! Mean semi-major axis, in meters, implied by the TLE's mean motion (rev/day)
+    ///! under the two-body assumption for the given gravitational parameter.
+    pub fn semi_major_axis(&self, mu: f64) -> f64 {
+        let mean_motion_rad_per_sec = self.mean_motion * 2.0 * PI / 86400.0;
+        (mu / mean_motion_rad_per_sec.powi(2)).cbrt()
+    }
+
+    ///! Build the mean Kepler elements for this TLE at its own epoch (`dt = 0`).
+    pub fn mean_elements(&self, mu: f64) -> KeplerElements {
+        KeplerElements::from_mean_anomaly(
+            self.semi_major_axis(mu),
+            self.eccentricity,
+            self.inclination,
+            self.raan,
+            self.argument_of_perigee,
+            self.mean_anomaly,
+        )
+    }
+}
+
+fn parse_field<T: std::str::FromStr>(field: &str) -> Result<T, String> {
+    field
+        .trim()
+        .parse::<T>()
+        .map_err(|_| format!("invalid TLE field: '{}'", field))
+}
+
+fn parse_bstar(mantissa: &str, exponent: &str) -> Result<f64, String> {
+    let mantissa = mantissa.trim();
+    if mantissa.is_empty() {
+        return Ok(0.0);
+    }
+    let sign = if mantissa.starts_with('-') { -1.0 } else { 1.0 };
+    let digits = mantissa.trim_start_matches(['+', '-']);
+    let value: f64 = format!("0.{}", digits)
+        .parse()
+        .map_err(|_| "invalid B* mantissa".to_string())?;
+    let exp: i32 = parse_field(exponent)?;
+    Ok(sign * value * 10f64.powi(exp))
+}
+
+#[cfg(test)]
+mod tle_tests {
+    use super::*;
+
+    const LINE1: &str = "1 25544U 98067A   24045.52849537  .00016717  00000-0  30721-3 0  9991";
+    const LINE2: &str = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.49589229439313";
+
+    #[test]
+    fn test_parse_tle() {
+        let tle = Tle::parse(LINE1, LINE2).unwrap();
+
+        assert_eq!(tle.catalog_number, 25544);
+        assert_eq!(tle.epoch_year, 24);
+        assert_relatively_eq(tle.epoch_day, 45.52849537, 1e-6);
+        assert_relatively_eq(tle.inclination, 51.6416 * PI / 180.0, 1e-9);
+        assert_relatively_eq(tle.raan, 247.4627 * PI / 180.0, 1e-9);
+        assert_relatively_eq(tle.eccentricity, 0.0006703, 1e-9);
+        assert_relatively_eq(tle.argument_of_perigee, 130.5360 * PI / 180.0, 1e-9);
+        assert_relatively_eq(tle.mean_anomaly, 325.0288 * PI / 180.0, 1e-9);
+        assert_relatively_eq(tle.mean_motion, 15.49589229, 1e-6);
+        assert_relatively_eq(tle.bstar, 0.30721e-3, 1e-9);
+    }
+
+    #[test]
+    fn test_parse_tle_rejects_short_lines() {
+        assert!(Tle::parse("too short", "also too short").is_err());
+    }
+
+    #[test]
+    fn test_tle_round_trips_through_json() {
+        let tle = Tle::parse(LINE1, LINE2).unwrap();
+
+        let json = serde_json::to_string(&tle).unwrap();
+        let recovered: Tle = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(recovered, tle);
+    }
+
+    #[test]
+    fn test_semi_major_axis_matches_leo_altitude() {
+        let tle = Tle::parse(LINE1, LINE2).unwrap();
+        let mu = 398600.4418e9;
+
+        // The ISS orbits at roughly 6790 km semi-major axis.
+        assert_relatively_eq(tle.semi_major_axis(mu), 6_796_000.0, 20_000.0);
+    }
+
+    fn assert_relatively_eq(num_one: f64, num_two: f64, epsilon: f64) {
+        let diff = (num_two - num_one).abs();
+        assert!(diff <= epsilon);
+    }
+}